@@ -0,0 +1,295 @@
+//! EIP-7594 (PeerDAS) cell API: computing and recovering the cells of a
+//! blob's Reed-Solomon extended evaluation polynomial.
+//!
+//! The recovery and (re-)proving math is performed by the same C library
+//! this crate already links against, via the `compute_cells_and_kzg_proofs`,
+//! `recover_cells_and_kzg_proofs`, and `verify_cell_kzg_proof_batch`
+//! routines it exposes for EIP-7594.
+
+use crate::bindings::C_KZG_RET;
+use crate::kzg_mainnet::{Blob, KzgSettings};
+use crate::{Bytes48, Error, KzgProof, BYTES_PER_FIELD_ELEMENT};
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::mem::MaybeUninit;
+
+/// Number of field elements in a single cell.
+pub const FIELD_ELEMENTS_PER_CELL: usize = 64;
+
+/// Number of bytes in a single cell.
+pub const BYTES_PER_CELL: usize = FIELD_ELEMENTS_PER_CELL * BYTES_PER_FIELD_ELEMENT;
+
+/// Number of cells a blob's Reed-Solomon extended evaluation polynomial is
+/// split into. A blob's 4096 evaluations are extended to 8192 and grouped
+/// into cells of [`FIELD_ELEMENTS_PER_CELL`] field elements each.
+pub const CELLS_PER_EXT_BLOB: usize = 128;
+
+/// A single cell: [`FIELD_ELEMENTS_PER_CELL`] field elements of a blob's
+/// Reed-Solomon extended evaluation polynomial. Same byte layout as the C
+/// library's `Cell`, so it can be passed across the FFI boundary directly.
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct Cell([u8; BYTES_PER_CELL]);
+
+impl Cell {
+    /// Builds a cell from raw bytes, failing if `bytes` is not exactly
+    /// [`BYTES_PER_CELL`] long.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != BYTES_PER_CELL {
+            return Err(Error::InvalidBytesLength(format!(
+                "Invalid byte length. Expected {} got {}",
+                BYTES_PER_CELL,
+                bytes.len()
+            )));
+        }
+        let mut array = [0u8; BYTES_PER_CELL];
+        array.copy_from_slice(bytes);
+        Ok(Self(array))
+    }
+
+    fn zeroed() -> Self {
+        Self([0u8; BYTES_PER_CELL])
+    }
+}
+
+impl AsRef<[u8]> for Cell {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Turns the C library's own [`C_KZG_RET`] (the same type every other
+/// operation in `bindings` converts) into our `Error`, keeping the ret code
+/// itself in the message instead of collapsing every failure kind together.
+fn check_ret(ret: C_KZG_RET, what: &str) -> Result<(), Error> {
+    if ret == C_KZG_RET::C_KZG_OK {
+        Ok(())
+    } else {
+        Err(Error::InvalidBytesLength(format!(
+            "{what} failed in the underlying C library: {ret:?}"
+        )))
+    }
+}
+
+/// Raw extern declarations for the C library's EIP-7594 routines, kept in
+/// their own module so they don't collide with the public wrapper functions
+/// of the same name below.
+mod ffi {
+    use super::{Blob, Bytes48, Cell, KzgProof, KzgSettings, C_KZG_RET};
+
+    extern "C" {
+        pub(super) fn compute_cells_and_kzg_proofs(
+            cells: *mut Cell,
+            proofs: *mut KzgProof,
+            blob: *const Blob,
+            s: *const KzgSettings,
+        ) -> C_KZG_RET;
+
+        pub(super) fn recover_cells_and_kzg_proofs(
+            recovered_cells: *mut Cell,
+            recovered_proofs: *mut KzgProof,
+            cell_indices: *const u64,
+            cells: *const Cell,
+            num_cells: u64,
+            s: *const KzgSettings,
+        ) -> C_KZG_RET;
+
+        pub(super) fn verify_cell_kzg_proof_batch(
+            ok: *mut bool,
+            commitments_bytes: *const Bytes48,
+            cell_indices: *const u64,
+            cells: *const Cell,
+            proofs_bytes: *const Bytes48,
+            num_cells: u64,
+            s: *const KzgSettings,
+        ) -> C_KZG_RET;
+    }
+}
+
+/// Computes all [`CELLS_PER_EXT_BLOB`] cells and their KZG proofs for `blob`.
+pub fn compute_cells_and_kzg_proofs(
+    blob: &Blob,
+    settings: &KzgSettings,
+) -> Result<(Vec<Cell>, Vec<KzgProof>), Error> {
+    let mut cells: Vec<Cell> = (0..CELLS_PER_EXT_BLOB).map(|_| Cell::zeroed()).collect();
+    let mut proofs: Vec<MaybeUninit<KzgProof>> = Vec::with_capacity(CELLS_PER_EXT_BLOB);
+
+    // SAFETY: `cells` has `CELLS_PER_EXT_BLOB` elements and `proofs` has
+    // capacity for as many, matching the counts the C library writes into them.
+    let ret = unsafe {
+        let ret = ffi::compute_cells_and_kzg_proofs(
+            cells.as_mut_ptr(),
+            proofs.as_mut_ptr().cast(),
+            blob,
+            settings,
+        );
+        proofs.set_len(CELLS_PER_EXT_BLOB);
+        ret
+    };
+    check_ret(ret, "compute_cells_and_kzg_proofs")?;
+
+    // SAFETY: the C call above initialized every element.
+    let proofs = unsafe { core::mem::transmute::<Vec<MaybeUninit<KzgProof>>, Vec<KzgProof>>(proofs) };
+    Ok((cells, proofs))
+}
+
+/// Verifies a batch of cell KZG proofs, one `(commitment, cell_index, cell,
+/// proof)` tuple per entry. Returns `Ok(true)` only if every proof in the
+/// batch is valid.
+pub fn verify_cell_kzg_proof_batch(
+    commitments: &[Bytes48],
+    cell_indices: &[u64],
+    cells: &[Cell],
+    proofs: &[Bytes48],
+    settings: &KzgSettings,
+) -> Result<bool, Error> {
+    if commitments.len() != cell_indices.len()
+        || cell_indices.len() != cells.len()
+        || cells.len() != proofs.len()
+    {
+        return Err(Error::InvalidBytesLength(
+            "commitments, cell_indices, cells, and proofs must be the same length".to_string(),
+        ));
+    }
+
+    let mut ok = false;
+    // SAFETY: all four input slices have the same length, as checked above.
+    let ret = unsafe {
+        ffi::verify_cell_kzg_proof_batch(
+            &mut ok,
+            commitments.as_ptr(),
+            cell_indices.as_ptr(),
+            cells.as_ptr(),
+            proofs.as_ptr(),
+            cells.len() as u64,
+            settings,
+        )
+    };
+    check_ret(ret, "verify_cell_kzg_proof_batch")?;
+    Ok(ok)
+}
+
+/// Recovers all [`CELLS_PER_EXT_BLOB`] cells and their KZG proofs from a
+/// sparse set of `(cell_index, Cell)` pairs, reconstructing the full
+/// evaluation polynomial via FFT-based erasure decoding over the roots of
+/// unity.
+///
+/// `cell_indices` and `cells` must be the same length, each index in
+/// `0..CELLS_PER_EXT_BLOB`, and indices must not repeat. At least half of
+/// the [`CELLS_PER_EXT_BLOB`] cells must be provided, otherwise the
+/// polynomial is under-determined and this returns
+/// [`Error::InvalidBytesLength`].
+pub fn recover_cells_and_kzg_proofs(
+    cell_indices: &[u64],
+    cells: &[Cell],
+    settings: &KzgSettings,
+) -> Result<(Vec<Cell>, Vec<KzgProof>), Error> {
+    validate_cell_indices(cell_indices, cells.len())?;
+
+    let mut recovered_cells: Vec<Cell> = (0..CELLS_PER_EXT_BLOB).map(|_| Cell::zeroed()).collect();
+    let mut recovered_proofs: Vec<MaybeUninit<KzgProof>> = Vec::with_capacity(CELLS_PER_EXT_BLOB);
+
+    // SAFETY: `cell_indices` and `cells` have the same, already-validated
+    // length, and the output buffers have room for `CELLS_PER_EXT_BLOB` elements.
+    let ret = unsafe {
+        let ret = ffi::recover_cells_and_kzg_proofs(
+            recovered_cells.as_mut_ptr(),
+            recovered_proofs.as_mut_ptr().cast(),
+            cell_indices.as_ptr(),
+            cells.as_ptr(),
+            cells.len() as u64,
+            settings,
+        );
+        recovered_proofs.set_len(CELLS_PER_EXT_BLOB);
+        ret
+    };
+    check_ret(ret, "recover_cells_and_kzg_proofs")?;
+
+    // SAFETY: the C call above initialized every element.
+    let recovered_proofs = unsafe {
+        core::mem::transmute::<Vec<MaybeUninit<KzgProof>>, Vec<KzgProof>>(recovered_proofs)
+    };
+    Ok((recovered_cells, recovered_proofs))
+}
+
+/// Validates that `cell_indices` (of which there are `num_cells`) are
+/// in-range, free of duplicates, and numerous enough to recover the full
+/// blob.
+fn validate_cell_indices(cell_indices: &[u64], num_cells: usize) -> Result<(), Error> {
+    if cell_indices.len() != num_cells {
+        return Err(Error::InvalidBytesLength(format!(
+            "cell_indices length {} does not match cells length {}",
+            cell_indices.len(),
+            num_cells
+        )));
+    }
+    if cell_indices.len() * 2 < CELLS_PER_EXT_BLOB {
+        return Err(Error::InvalidBytesLength(format!(
+            "need at least {} cells to recover, got {}",
+            CELLS_PER_EXT_BLOB / 2,
+            cell_indices.len()
+        )));
+    }
+
+    let mut seen = [false; CELLS_PER_EXT_BLOB];
+    for &index in cell_indices {
+        let index = index as usize;
+        if index >= CELLS_PER_EXT_BLOB {
+            return Err(Error::InvalidBytesLength(format!(
+                "cell index {index} out of range, must be < {CELLS_PER_EXT_BLOB}"
+            )));
+        }
+        if seen[index] {
+            return Err(Error::InvalidBytesLength(format!(
+                "duplicate cell index {index}"
+            )));
+        }
+        seen[index] = true;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cell_round_trips_bytes() {
+        let bytes = vec![0x11u8; BYTES_PER_CELL];
+        let cell = Cell::from_bytes(&bytes).unwrap();
+        assert_eq!(cell.as_ref(), bytes.as_slice());
+    }
+
+    #[test]
+    fn cell_rejects_wrong_length() {
+        assert!(Cell::from_bytes(&[0u8; BYTES_PER_CELL - 1]).is_err());
+    }
+
+    #[test]
+    fn rejects_too_few_cells() {
+        let indices: Vec<u64> = (0..(CELLS_PER_EXT_BLOB as u64 / 2 - 1)).collect();
+        assert!(validate_cell_indices(&indices, indices.len()).is_err());
+    }
+
+    #[test]
+    fn rejects_out_of_range_index() {
+        let indices = vec![CELLS_PER_EXT_BLOB as u64];
+        let indices: Vec<u64> = (0..(CELLS_PER_EXT_BLOB as u64 / 2 - 1))
+            .chain(indices)
+            .collect();
+        assert!(validate_cell_indices(&indices, indices.len()).is_err());
+    }
+
+    #[test]
+    fn rejects_duplicate_index() {
+        let indices = vec![0u64; CELLS_PER_EXT_BLOB / 2];
+        assert!(validate_cell_indices(&indices, indices.len()).is_err());
+    }
+
+    #[test]
+    fn accepts_exactly_half_unique_in_range_indices() {
+        let indices: Vec<u64> = (0..(CELLS_PER_EXT_BLOB as u64 / 2)).collect();
+        assert!(validate_cell_indices(&indices, indices.len()).is_ok());
+    }
+}
@@ -0,0 +1,122 @@
+use crate::kzg_mainnet::{Blob, FIELD_ELEMENTS_PER_BLOB};
+use crate::{BYTES_PER_FIELD_ELEMENT, Error};
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+/// Only 31 of the 32 bytes in a field element are usable: the high byte of
+/// every element must stay zero so the value never exceeds the BLS12-381
+/// scalar field modulus.
+const USABLE_BYTES_PER_FIELD_ELEMENT: usize = BYTES_PER_FIELD_ELEMENT - 1;
+
+/// Usable payload capacity of a single blob, in bytes.
+const USABLE_BYTES_PER_BLOB: usize = FIELD_ELEMENTS_PER_BLOB * USABLE_BYTES_PER_FIELD_ELEMENT;
+
+/// Size of the length header prepended to the first element's payload.
+const LENGTH_HEADER_BYTES: usize = 8;
+
+/// Packs arbitrary application `data` into one or more valid blobs.
+///
+/// `data` is split into 31-byte chunks, each written as the low 31 bytes of
+/// a field element so the high byte is always zero. A `u64` length header is
+/// prepended ahead of the payload so [`decode`] can strip the padding added
+/// to the final, partially-filled element. When `data` exceeds the capacity
+/// of a single blob, it is split across multiple blobs.
+pub fn encode(data: &[u8]) -> Result<Vec<Blob>, Error> {
+    let mut payload = Vec::with_capacity(LENGTH_HEADER_BYTES + data.len());
+    payload.extend_from_slice(&(data.len() as u64).to_be_bytes());
+    payload.extend_from_slice(data);
+
+    // `payload` always holds at least the length header, so `chunks` always
+    // yields at least one (possibly padded) chunk.
+    payload
+        .chunks(USABLE_BYTES_PER_BLOB)
+        .map(encode_one)
+        .collect()
+}
+
+/// Packs up to [`USABLE_BYTES_PER_BLOB`] bytes into a single blob, zero-padding
+/// the final partial element.
+fn encode_one(chunk: &[u8]) -> Result<Blob, Error> {
+    let mut bytes = vec![0u8; FIELD_ELEMENTS_PER_BLOB * BYTES_PER_FIELD_ELEMENT];
+    for (i, element_payload) in chunk.chunks(USABLE_BYTES_PER_FIELD_ELEMENT).enumerate() {
+        let start = i * BYTES_PER_FIELD_ELEMENT;
+        // Leave bytes[start] (the high byte) zero and write the payload into
+        // the remaining 31 bytes of the element.
+        bytes[start + 1..start + 1 + element_payload.len()].copy_from_slice(element_payload);
+    }
+    Blob::from_bytes(&bytes)
+}
+
+/// Reverses [`encode`], reconstructing the original application bytes from
+/// one or more blobs.
+///
+/// Returns [`Error::InvalidBytesLength`] if the high byte of any field
+/// element is non-zero, since such a blob could not have been produced by
+/// [`encode`].
+pub fn decode(blobs: &[Blob]) -> Result<Vec<u8>, Error> {
+    let mut payload = Vec::with_capacity(blobs.len() * USABLE_BYTES_PER_BLOB);
+    for blob in blobs {
+        let bytes: &[u8] = blob.as_ref();
+        for element in bytes.chunks(BYTES_PER_FIELD_ELEMENT) {
+            if element[0] != 0 {
+                return Err(Error::InvalidBytesLength(
+                    "non-zero high byte in blob field element".to_string(),
+                ));
+            }
+            payload.extend_from_slice(&element[1..]);
+        }
+    }
+
+    if payload.len() < LENGTH_HEADER_BYTES {
+        return Err(Error::InvalidBytesLength(
+            "blob payload shorter than length header".to_string(),
+        ));
+    }
+    let mut len_bytes = [0u8; LENGTH_HEADER_BYTES];
+    len_bytes.copy_from_slice(&payload[..LENGTH_HEADER_BYTES]);
+    let len = u64::from_be_bytes(len_bytes) as usize;
+
+    let data = &payload[LENGTH_HEADER_BYTES..];
+    if len > data.len() {
+        return Err(Error::InvalidBytesLength(
+            "length header exceeds available payload".to_string(),
+        ));
+    }
+    Ok(data[..len].to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_short_data() {
+        let data = b"hello kzg".to_vec();
+        let blobs = encode(&data).unwrap();
+        assert_eq!(blobs.len(), 1);
+        assert_eq!(decode(&blobs).unwrap(), data);
+    }
+
+    #[test]
+    fn round_trips_empty_data() {
+        let blobs = encode(&[]).unwrap();
+        assert_eq!(blobs.len(), 1);
+        assert_eq!(decode(&blobs).unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn splits_across_multiple_blobs() {
+        let data = vec![0xabu8; USABLE_BYTES_PER_BLOB * 2 + 10];
+        let blobs = encode(&data).unwrap();
+        assert_eq!(blobs.len(), 3);
+        assert_eq!(decode(&blobs).unwrap(), data);
+    }
+
+    #[test]
+    fn rejects_non_zero_high_byte() {
+        let mut bytes = vec![0u8; FIELD_ELEMENTS_PER_BLOB * BYTES_PER_FIELD_ELEMENT];
+        bytes[0] = 1;
+        let blob = Blob::from_bytes(&bytes).unwrap();
+        assert!(decode(&[blob]).is_err());
+    }
+}
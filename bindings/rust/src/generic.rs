@@ -0,0 +1,153 @@
+//! A dimension-parameterized blob type for blob sizes other than the
+//! `kzg_mainnet` / `kzg_minimal` presets.
+//!
+//! The underlying C library is compiled once per preset, so only
+//! `FIELD_ELEMENTS_PER_BLOB` values of 4096 and 4 actually have KZG settings
+//! available. `blob_to_kzg_commitment`/`compute_blob_kzg_proof` are therefore
+//! only provided as concrete `impl` blocks on the `N = 4096`
+//! ([`MainnetBlob`]) and `N = 4` ([`MinimalBlob`]) instantiations, not as a
+//! generic method on `Blob<N>` for arbitrary `N` — there is no runtime
+//! fallback for other dimensions, they simply don't have those methods.
+//! Custom dimensions can still be stored and round-tripped via
+//! [`Blob::from_bytes`]/[`Blob::as_ref`], but get no KZG operations until the
+//! C library itself supports arbitrary `FIELD_ELEMENTS_PER_BLOB`.
+
+use crate::kzg_mainnet;
+use crate::kzg_minimal;
+use crate::{BYTES_PER_FIELD_ELEMENT, Error, KzgCommitment, KzgProof};
+use alloc::boxed::Box;
+
+/// A blob of `FIELD_ELEMENTS_PER_BLOB` field elements, each
+/// [`BYTES_PER_FIELD_ELEMENT`] bytes, for an arbitrary (ideally power-of-two)
+/// `FIELD_ELEMENTS_PER_BLOB`.
+#[derive(Clone)]
+pub struct Blob<const FIELD_ELEMENTS_PER_BLOB: usize> {
+    bytes: Box<[u8]>,
+}
+
+impl<const FIELD_ELEMENTS_PER_BLOB: usize> Blob<FIELD_ELEMENTS_PER_BLOB> {
+    /// Total size of a blob with this dimension, in bytes.
+    pub const BYTES_PER_BLOB: usize = FIELD_ELEMENTS_PER_BLOB * BYTES_PER_FIELD_ELEMENT;
+
+    /// Builds a blob from raw bytes, failing if `bytes` is not exactly
+    /// [`Self::BYTES_PER_BLOB`] long.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != Self::BYTES_PER_BLOB {
+            return Err(Error::InvalidBytesLength(format!(
+                "Invalid byte length. Expected {} got {}",
+                Self::BYTES_PER_BLOB,
+                bytes.len()
+            )));
+        }
+        Ok(Self {
+            bytes: bytes.to_vec().into_boxed_slice(),
+        })
+    }
+}
+
+impl<const FIELD_ELEMENTS_PER_BLOB: usize> AsRef<[u8]> for Blob<FIELD_ELEMENTS_PER_BLOB> {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+/// The `kzg_mainnet` dimension (4096 field elements per blob).
+pub type MainnetBlob = Blob<4096>;
+
+/// The `kzg_minimal` dimension (4 field elements per blob).
+pub type MinimalBlob = Blob<4>;
+
+impl From<kzg_mainnet::Blob> for MainnetBlob {
+    fn from(blob: kzg_mainnet::Blob) -> Self {
+        // `kzg_mainnet::Blob` is always `BYTES_PER_BLOB` bytes, so this can't fail.
+        Blob::from_bytes(blob.as_ref()).expect("kzg_mainnet::Blob has the mainnet blob length")
+    }
+}
+
+impl TryFrom<MainnetBlob> for kzg_mainnet::Blob {
+    type Error = Error;
+
+    fn try_from(blob: MainnetBlob) -> Result<Self, Error> {
+        kzg_mainnet::Blob::from_bytes(blob.as_ref())
+    }
+}
+
+impl From<kzg_minimal::Blob> for MinimalBlob {
+    fn from(blob: kzg_minimal::Blob) -> Self {
+        Blob::from_bytes(blob.as_ref()).expect("kzg_minimal::Blob has the minimal blob length")
+    }
+}
+
+impl TryFrom<MinimalBlob> for kzg_minimal::Blob {
+    type Error = Error;
+
+    fn try_from(blob: MinimalBlob) -> Result<Self, Error> {
+        kzg_minimal::Blob::from_bytes(blob.as_ref())
+    }
+}
+
+impl MainnetBlob {
+    /// Computes the KZG commitment for this blob using the mainnet settings.
+    pub fn blob_to_kzg_commitment(
+        &self,
+        settings: &kzg_mainnet::KzgSettings,
+    ) -> Result<KzgCommitment, Error> {
+        kzg_mainnet::Blob::from_bytes(self.as_ref())?.blob_to_kzg_commitment(settings)
+    }
+
+    /// Computes the KZG proof for this blob using the mainnet settings.
+    pub fn compute_blob_kzg_proof(
+        &self,
+        commitment: &KzgCommitment,
+        settings: &kzg_mainnet::KzgSettings,
+    ) -> Result<KzgProof, Error> {
+        kzg_mainnet::Blob::from_bytes(self.as_ref())?
+            .compute_blob_kzg_proof(commitment, settings)
+    }
+}
+
+impl MinimalBlob {
+    /// Computes the KZG commitment for this blob using the minimal settings.
+    pub fn blob_to_kzg_commitment(
+        &self,
+        settings: &kzg_minimal::KzgSettings,
+    ) -> Result<KzgCommitment, Error> {
+        kzg_minimal::Blob::from_bytes(self.as_ref())?.blob_to_kzg_commitment(settings)
+    }
+
+    /// Computes the KZG proof for this blob using the minimal settings.
+    pub fn compute_blob_kzg_proof(
+        &self,
+        commitment: &KzgCommitment,
+        settings: &kzg_minimal::KzgSettings,
+    ) -> Result<KzgProof, Error> {
+        kzg_minimal::Blob::from_bytes(self.as_ref())?
+            .compute_blob_kzg_proof(commitment, settings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_bytes_rejects_wrong_length() {
+        assert!(Blob::<4096>::from_bytes(&[0u8; 10]).is_err());
+    }
+
+    #[test]
+    fn from_bytes_accepts_matching_length() {
+        let bytes = vec![0u8; MainnetBlob::BYTES_PER_BLOB];
+        let blob = MainnetBlob::from_bytes(&bytes).unwrap();
+        assert_eq!(blob.as_ref(), bytes.as_slice());
+    }
+
+    #[test]
+    fn round_trips_through_kzg_mainnet_blob() {
+        let bytes = vec![0u8; MainnetBlob::BYTES_PER_BLOB];
+        let preset_blob = kzg_mainnet::Blob::from_bytes(&bytes).unwrap();
+        let generic_blob: MainnetBlob = preset_blob.into();
+        let back: kzg_mainnet::Blob = generic_blob.try_into().unwrap();
+        assert_eq!(back.as_ref(), bytes.as_slice());
+    }
+}
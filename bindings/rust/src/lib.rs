@@ -9,6 +9,12 @@ extern crate alloc;
 extern crate blst;
 
 mod bindings;
+pub mod cell;
+pub mod encode;
+pub mod generic;
+#[cfg(feature = "serde")]
+mod serde_support;
+mod trusted_setup;
 
 // Expose relevant types with idiomatic names.
 pub use bindings::{
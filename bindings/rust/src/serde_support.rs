@@ -0,0 +1,85 @@
+//! `serde` implementations for the crate's public types, gated by the
+//! `serde` feature.
+//!
+//! Human-readable formats (JSON, YAML, ...) use lowercase `0x`-prefixed hex;
+//! binary formats (bincode, ...) use raw bytes.
+
+use crate::kzg_mainnet::Blob;
+use crate::{Bytes32, Bytes48, KzgCommitment, KzgProof};
+use core::fmt;
+use serde::de::{Error as DeError, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Implements human-readable-aware `Serialize`/`Deserialize` for a type that
+/// already has `to_hex`/`from_hex` and `as_ref`/`from_bytes`.
+macro_rules! impl_hex_serde {
+    ($type:ty, $name:literal) => {
+        impl Serialize for $type {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    serializer.serialize_str(&self.to_hex())
+                } else {
+                    serializer.serialize_bytes(self.as_ref())
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $type {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct HexOrBytesVisitor;
+
+                impl<'de> Visitor<'de> for HexOrBytesVisitor {
+                    type Value = $type;
+
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        write!(f, "a hex string or raw bytes for {}", $name)
+                    }
+
+                    fn visit_str<E: DeError>(self, v: &str) -> Result<Self::Value, E> {
+                        <$type>::from_hex(v).map_err(DeError::custom)
+                    }
+
+                    fn visit_bytes<E: DeError>(self, v: &[u8]) -> Result<Self::Value, E> {
+                        <$type>::from_bytes(v).map_err(DeError::custom)
+                    }
+                }
+
+                if deserializer.is_human_readable() {
+                    deserializer.deserialize_str(HexOrBytesVisitor)
+                } else {
+                    deserializer.deserialize_bytes(HexOrBytesVisitor)
+                }
+            }
+        }
+    };
+}
+
+impl_hex_serde!(Bytes32, "Bytes32");
+impl_hex_serde!(Bytes48, "Bytes48");
+impl_hex_serde!(KzgCommitment, "KzgCommitment");
+impl_hex_serde!(KzgProof, "KzgProof");
+impl_hex_serde!(Blob, "Blob");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes48_round_trips_through_json() {
+        let bytes = [7u8; 48];
+        let original = Bytes48::from_bytes(&bytes).unwrap();
+        let json = serde_json::to_string(&original).unwrap();
+        assert_eq!(json, alloc::format!("\"{}\"", original.to_hex()));
+        let decoded: Bytes48 = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.as_ref(), original.as_ref());
+    }
+
+    #[test]
+    fn bytes48_round_trips_through_bincode() {
+        let bytes = [9u8; 48];
+        let original = Bytes48::from_bytes(&bytes).unwrap();
+        let encoded = bincode::serialize(&original).unwrap();
+        let decoded: Bytes48 = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(decoded.as_ref(), original.as_ref());
+    }
+}
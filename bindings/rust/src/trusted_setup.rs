@@ -0,0 +1,74 @@
+//! Trusted-setup loading that doesn't depend on `std::fs`/`std::io`, so
+//! embedded and WASM consumers (and anyone else who'd rather not touch a
+//! filesystem) can initialize a [`KzgSettings`] too. Unlike
+//! `load_trusted_setup_file`, these loaders work the same whether or not
+//! the `std` feature is enabled.
+
+use crate::kzg_mainnet::KzgSettings;
+use crate::Error;
+use alloc::format;
+use alloc::string::String;
+use core2::io::Read;
+
+impl KzgSettings {
+    /// Loads a trusted setup by reading it to completion from `reader`.
+    pub fn load_trusted_setup_reader<R: Read>(reader: &mut R) -> Result<Self, Error> {
+        let mut contents = String::new();
+        reader
+            .read_to_string(&mut contents)
+            .map_err(|e| Error::InvalidTrustedSetup(format!("failed to read trusted setup: {e}")))?;
+        Self::load_trusted_setup_str(&contents)
+    }
+
+    /// Loads a trusted setup directly from its textual byte representation,
+    /// e.g. bytes produced by `include_bytes!` or held in a `&'static [u8]`.
+    pub fn load_trusted_setup_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let contents = core::str::from_utf8(bytes).map_err(|e| {
+            Error::InvalidTrustedSetup(format!("trusted setup is not valid utf-8: {e}"))
+        })?;
+        Self::load_trusted_setup_str(contents)
+    }
+}
+
+/// The mainnet trusted setup, embedded at compile time. Enabled by the
+/// `embedded-trusted-setup` feature.
+#[cfg(feature = "embedded-trusted-setup")]
+pub static MAINNET_TRUSTED_SETUP: &[u8] = include_bytes!("../../../src/trusted_setup.txt");
+
+#[cfg(feature = "embedded-trusted-setup")]
+impl KzgSettings {
+    /// Loads the trusted setup embedded at compile time via
+    /// [`MAINNET_TRUSTED_SETUP`].
+    pub fn load_embedded_trusted_setup() -> Result<Self, Error> {
+        Self::load_trusted_setup_bytes(MAINNET_TRUSTED_SETUP)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Shared between the reader- and byte-slice-based loaders below so
+    /// they're exercised against exactly the same content. Not a
+    /// cryptographically valid trusted setup (this tree has no such fixture
+    /// to pull from) — only format/parsing behavior is in scope here.
+    const SETUP_FIXTURE: &str = "1\n1\n1\n0x0\n";
+
+    #[test]
+    fn load_trusted_setup_bytes_rejects_non_utf8() {
+        let err = KzgSettings::load_trusted_setup_bytes(&[0xff, 0xfe]).unwrap_err();
+        assert!(matches!(err, Error::InvalidTrustedSetup(_)));
+    }
+
+    #[test]
+    fn load_trusted_setup_reader_matches_bytes() {
+        let mut reader = SETUP_FIXTURE.as_bytes();
+        let from_reader = KzgSettings::load_trusted_setup_reader(&mut reader);
+        let from_bytes = KzgSettings::load_trusted_setup_bytes(SETUP_FIXTURE.as_bytes());
+        // Compare the full `Result` (not just `is_ok()`) so this actually
+        // checks the reader path captured the same bytes the direct
+        // byte-slice path did, rather than agreeing on success/failure by
+        // construction (both ultimately call `load_trusted_setup_str`).
+        assert_eq!(format!("{from_reader:?}"), format!("{from_bytes:?}"));
+    }
+}